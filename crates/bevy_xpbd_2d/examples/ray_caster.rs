@@ -73,8 +73,9 @@ fn transform_parent(mut parent: Query<&mut Transform, With<ParentTransform>>, ti
     }
 }
 
-// Note: The `PhysicsDebugPlugin` can also render rays, hit points, and normals.
-//       This system is primarily for demonstration purposes.
+// Note: The `PhysicsDebugPlugin` can also render rays, hit points, and normals, and
+//       its look can be tuned per-category through the `PhysicsGizmos` gizmo config
+//       group. This system is primarily for demonstration purposes.
 fn render_rays(
     mut rays: Query<(&mut RayCaster, &mut RayHits)>,
     mut gizmos: Gizmos,
@@ -86,44 +87,31 @@ fn render_rays(
         let direction = ray.global_direction().f32();
 
         for hit in hits.iter() {
-            let mut some_ricochet: Option<RayHitData> = Some(*hit);
+            let first_hit_location = origin + direction * hit.time_of_impact as f32;
+            gizmos.line_2d(origin, first_hit_location, Color::GREEN);
 
-            let mut n_hits = 1;
-            let mut last_hit_location = origin + direction * hit.time_of_impact as f32;
-            gizmos.line_2d(origin, last_hit_location, Color::GREEN);
-            while some_ricochet.is_some() && n_hits < 64 {
-                if let Some(ricochet) = some_ricochet {
-                    some_ricochet = sq.cast_ray(
-                        last_hit_location,
-                        Direction2d::new_unchecked(ricochet.normal),
-                        1000.0,
-                        true,
-                        SpatialQueryFilter::default().with_excluded_entities([ricochet.entity]),
-                    );
+            // Let `SpatialQuery` do the ricocheting for us, including reflecting off
+            // this very first hit, instead of re-deriving mirror reflections by hand.
+            // Casting from the caster's own origin (rather than from `first_hit_location`
+            // with the unreflected `direction`) is what makes the first bounce an
+            // actual reflection instead of a straight continuation through the wall.
+            let bounces = sq.cast_ray_reflected(
+                ray.global_origin(),
+                ray.global_direction(),
+                1000.0,
+                64,
+                true,
+                SpatialQueryFilter::default(),
+            );
 
-                    if let Some(this_hit) = some_ricochet {
-                        if this_hit.time_of_impact == 0. {
-                            break;
-                        }
-
-                        let new_hit_location =
-                            last_hit_location + (ricochet.normal) * this_hit.time_of_impact as f32;
-
-                        gizmos.circle_2d(last_hit_location, 5., Color::YELLOW);
-                        gizmos.arrow_2d(last_hit_location, new_hit_location, Color::GREEN);
-                        last_hit_location = new_hit_location;
-                    } else {
-                        gizmos.circle_2d(last_hit_location, 5., Color::ORANGE);
-                        gizmos.ray_2d(
-                            last_hit_location,
-                            ricochet.normal * 1000.,
-                            Color::ORANGE_RED,
-                        );
-                    }
-                } else {
-                    break;
-                }
-                n_hits += 1;
+            // `bounces[0]` is the same hit already drawn above; only the reflections
+            // after it are new.
+            let mut last_location = first_hit_location;
+            for bounce in bounces.iter().skip(1) {
+                let bounce_location = bounce.point.f32();
+                gizmos.circle_2d(last_location, 5., Color::YELLOW);
+                gizmos.arrow_2d(last_location, bounce_location, Color::GREEN);
+                last_location = bounce_location;
             }
         }
         if hits.is_empty() {
@@ -0,0 +1,176 @@
+//! Core components for rigid bodies and colliders.
+
+use bevy::prelude::*;
+
+use crate::math::*;
+
+/// Determines how a body is affected by forces, torques, and constraints.
+///
+/// This is a minimal stand-in for the full rigid body component; only the variants
+/// needed by the spatial query APIs are represented here.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RigidBody {
+    /// A dynamic body is affected by forces, torques, and contacts.
+    #[default]
+    Dynamic,
+    /// A static body remains fixed in place and is not affected by other bodies.
+    Static,
+    /// A kinematic body is moved programmatically rather than by forces.
+    Kinematic,
+}
+
+/// Angular velocity of a [`RigidBody`], in radians per second.
+#[derive(Component, Clone, Copy, Debug, Default, Deref, DerefMut, PartialEq)]
+pub struct AngularVelocity(pub Scalar);
+
+/// The shape used by a [`Collider`] for narrow-phase collision detection and
+/// spatial queries.
+#[derive(Clone, Debug)]
+pub(crate) enum ColliderShape {
+    /// A circle centered on the collider's origin.
+    Circle {
+        /// The radius of the circle.
+        radius: Scalar,
+    },
+    /// An axis-aligned rectangle centered on the collider's origin.
+    ///
+    /// Spatial queries do not yet take the collider's rotation into account for
+    /// rectangles; only the translation is applied.
+    Rectangle {
+        /// Half of the rectangle's width and height.
+        half_extents: Vector,
+    },
+}
+
+impl ColliderShape {
+    /// The radius of the smallest circle, centered on the collider's origin, that
+    /// fully contains the shape. Used to approximate non-circular shapes when
+    /// sweeping a [`ShapeCaster`](crate::prelude::ShapeCaster).
+    pub(crate) fn bounding_radius(&self) -> Scalar {
+        match self {
+            ColliderShape::Circle { radius } => *radius,
+            ColliderShape::Rectangle { half_extents } => half_extents.length(),
+        }
+    }
+
+    /// Returns this shape grown outward by `radius` in every direction.
+    ///
+    /// This is used to turn a shape cast into a ray cast via the usual Minkowski sum
+    /// trick: sweeping a circle of radius `r` against a shape is equivalent to
+    /// casting a ray against that shape grown by `r`. For non-circular shapes the
+    /// grown corners are approximated as square rather than rounded.
+    pub(crate) fn inflated(&self, radius: Scalar) -> ColliderShape {
+        match self {
+            ColliderShape::Circle { radius: r } => ColliderShape::Circle { radius: r + radius },
+            ColliderShape::Rectangle { half_extents } => ColliderShape::Rectangle {
+                half_extents: *half_extents + Vector::splat(radius),
+            },
+        }
+    }
+}
+
+/// The physical shape of an entity used for collision detection and spatial queries.
+#[derive(Component, Clone, Debug)]
+pub struct Collider {
+    pub(crate) shape: ColliderShape,
+}
+
+impl Collider {
+    /// Creates a collider with a [circle](ColliderShape::Circle) shape.
+    pub fn circle(radius: Scalar) -> Self {
+        Self {
+            shape: ColliderShape::Circle { radius },
+        }
+    }
+
+    /// Creates a collider with an axis-aligned [rectangle](ColliderShape::Rectangle)
+    /// shape of the given width and height.
+    pub fn rectangle(width: Scalar, height: Scalar) -> Self {
+        Self {
+            shape: ColliderShape::Rectangle {
+                half_extents: Vector::new(width, height) / 2.0,
+            },
+        }
+    }
+
+    /// Creates a collider representing the given 2D geometric primitive exactly, so
+    /// that a [`Collider`] does not have to be hand-built just to sweep or query one.
+    ///
+    /// Only primitives [`ColliderShape`] can represent exactly — currently [`Circle`]
+    /// and [`Rectangle`] — implement the `Into<Collider>` this requires. For a
+    /// primitive with no exact representation, such as [`Ellipse`], [`Capsule2d`], or
+    /// [`RegularPolygon`], use
+    /// [`approximate_bounding_circle`](Self::approximate_bounding_circle) instead, which
+    /// makes the resulting precision loss explicit at the call site.
+    pub fn from_primitive(primitive: impl Into<Collider>) -> Self {
+        primitive.into()
+    }
+
+    /// Creates a collider approximating a primitive with no exact [`ColliderShape`]
+    /// representation by its bounding circle.
+    ///
+    /// This is lossy by design: a thin [`Ellipse`] or [`Capsule2d`], or a
+    /// low-vertex-count [`RegularPolygon`], sweeps and queries as a much larger disc.
+    /// Unlike [`from_primitive`](Self::from_primitive), this method's name says so, so
+    /// that accepting the approximation is something a caller opts into rather than
+    /// something that happens to them unnoticed.
+    pub fn approximate_bounding_circle(primitive: impl Into<ApproximatedPrimitive>) -> Self {
+        Collider::circle(primitive.into().bounding_radius())
+    }
+}
+
+/// A 2D geometric primitive with no exact representation in [`ColliderShape`], so
+/// [`Collider::approximate_bounding_circle`] can only approximate it by the smallest
+/// circle that fully contains it.
+pub enum ApproximatedPrimitive {
+    /// See [`bevy::math::primitives::Ellipse`].
+    Ellipse(bevy::math::primitives::Ellipse),
+    /// See [`bevy::math::primitives::Capsule2d`].
+    Capsule2d(bevy::math::primitives::Capsule2d),
+    /// See [`bevy::math::primitives::RegularPolygon`].
+    RegularPolygon(bevy::math::primitives::RegularPolygon),
+}
+
+impl ApproximatedPrimitive {
+    fn bounding_radius(&self) -> Scalar {
+        match self {
+            Self::Ellipse(ellipse) => ellipse.half_size.max_element() as Scalar,
+            Self::Capsule2d(capsule) => (capsule.radius + capsule.half_length) as Scalar,
+            Self::RegularPolygon(polygon) => polygon.circumcircle.radius as Scalar,
+        }
+    }
+}
+
+impl From<bevy::math::primitives::Ellipse> for ApproximatedPrimitive {
+    fn from(ellipse: bevy::math::primitives::Ellipse) -> Self {
+        Self::Ellipse(ellipse)
+    }
+}
+
+impl From<bevy::math::primitives::Capsule2d> for ApproximatedPrimitive {
+    fn from(capsule: bevy::math::primitives::Capsule2d) -> Self {
+        Self::Capsule2d(capsule)
+    }
+}
+
+impl From<bevy::math::primitives::RegularPolygon> for ApproximatedPrimitive {
+    fn from(polygon: bevy::math::primitives::RegularPolygon) -> Self {
+        Self::RegularPolygon(polygon)
+    }
+}
+
+impl From<bevy::math::primitives::Circle> for Collider {
+    fn from(circle: bevy::math::primitives::Circle) -> Self {
+        Collider::circle(circle.radius as Scalar)
+    }
+}
+
+impl From<bevy::math::primitives::Rectangle> for Collider {
+    fn from(rectangle: bevy::math::primitives::Rectangle) -> Self {
+        Collider::rectangle(
+            rectangle.half_size.x as Scalar * 2.0,
+            rectangle.half_size.y as Scalar * 2.0,
+        )
+    }
+}
+
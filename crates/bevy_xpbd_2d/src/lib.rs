@@ -0,0 +1,11 @@
+//! **bevy_xpbd_2d** is a 2D physics engine based on Extended Position Based Dynamics
+//! (XPBD) for the [Bevy](https://bevyengine.org/) game engine.
+//!
+//! This file only tracks the pieces of the crate touched by recent work in this
+//! tree; see the crate's own documentation for the full picture.
+
+pub mod components;
+pub mod math;
+pub mod plugins;
+pub mod prelude;
+pub mod spatial_query;
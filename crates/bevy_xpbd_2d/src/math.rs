@@ -0,0 +1,82 @@
+//! Math types used throughout `bevy_xpbd_2d`.
+//!
+//! The crate is generic over the scalar type used for physics calculations so that
+//! it can be built with either `f32` (the default) or `f64` precision via the `f64`
+//! feature flag. Code in the crate (and in user code) should prefer [`Scalar`] and
+//! [`Vector`] over hardcoding `f32`/`Vec2` so that it keeps working either way.
+
+#[cfg(feature = "f64")]
+mod scalar {
+    /// The scalar type used for physics calculations.
+    pub type Scalar = f64;
+    /// The vector type used for physics calculations.
+    pub type Vector = bevy::math::DVec2;
+}
+
+#[cfg(not(feature = "f64"))]
+mod scalar {
+    /// The scalar type used for physics calculations.
+    pub type Scalar = f32;
+    /// The vector type used for physics calculations.
+    pub type Vector = bevy::math::Vec2;
+}
+
+pub use bevy::math::Direction2d;
+pub use scalar::*;
+
+/// A conversion trait for going from the crate's [`Scalar`]-based math types to
+/// their `f32` equivalents, primarily so that they can be handed to rendering and
+/// gizmo APIs that always work in `f32`.
+pub trait AsF32 {
+    /// The `f32` equivalent of `Self`.
+    type F32;
+
+    /// Converts `self` to its `f32` equivalent.
+    fn f32(self) -> Self::F32;
+}
+
+impl AsF32 for Vector {
+    type F32 = bevy::math::Vec2;
+
+    #[cfg(feature = "f64")]
+    fn f32(self) -> Self::F32 {
+        self.as_vec2()
+    }
+
+    #[cfg(not(feature = "f64"))]
+    fn f32(self) -> Self::F32 {
+        self
+    }
+}
+
+impl AsF32 for Direction2d {
+    type F32 = bevy::math::Vec2;
+
+    fn f32(self) -> Self::F32 {
+        *self
+    }
+}
+
+/// A conversion trait for going from `f32`, the precision Bevy's own types like
+/// [`Camera`](bevy::render::camera::Camera) work in, to the crate's own [`Scalar`].
+pub trait AdjustPrecision {
+    /// The [`Scalar`]-precision equivalent of `Self`.
+    type Adjusted;
+
+    /// Converts `self` to the crate's [`Scalar`] precision.
+    fn adjust_precision(self) -> Self::Adjusted;
+}
+
+impl AdjustPrecision for bevy::math::Vec2 {
+    type Adjusted = Vector;
+
+    #[cfg(feature = "f64")]
+    fn adjust_precision(self) -> Self::Adjusted {
+        self.as_dvec2()
+    }
+
+    #[cfg(not(feature = "f64"))]
+    fn adjust_precision(self) -> Self::Adjusted {
+        self
+    }
+}
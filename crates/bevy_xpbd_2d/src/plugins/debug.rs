@@ -0,0 +1,114 @@
+use bevy::{
+    gizmos::config::{GizmoConfigGroup, GizmoConfigStore},
+    prelude::*,
+};
+
+use crate::{math::*, prelude::*};
+
+/// The [`GizmoConfigGroup`] used to configure the debug visuals that
+/// [`PhysicsDebugPlugin`] draws for [`RayCaster`]s.
+///
+/// Like any other gizmo config group, it is configured through [`GizmoConfigStore`]:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_xpbd_2d::prelude::*;
+/// fn style_ray_gizmos(mut config_store: ResMut<GizmoConfigStore>) {
+///     let (_, physics_gizmos) = config_store.config_mut::<PhysicsGizmos>();
+///     physics_gizmos.ray_color = Some(Color::RED);
+/// }
+/// ```
+#[derive(Clone, Debug, Reflect, GizmoConfigGroup)]
+pub struct PhysicsGizmos {
+    /// Draws the cast ray itself, from its origin to each hit point, so the drawn
+    /// line reaches the farthest hit (or its full length, if nothing was hit).
+    pub rays_enabled: bool,
+    /// The color of a drawn ray. `None` falls back to a sensible default.
+    pub ray_color: Option<Color>,
+    /// Draws a circle at every hit point.
+    pub hit_points_enabled: bool,
+    /// The color of a drawn hit point.
+    pub hit_point_color: Option<Color>,
+    /// The radius of the circle drawn at a hit point.
+    pub hit_point_radius: f32,
+    /// Draws the surface normal at every hit point.
+    pub normals_enabled: bool,
+    /// The color of a drawn surface normal.
+    pub normal_color: Option<Color>,
+    /// The length of the line drawn for a surface normal.
+    pub normal_length: f32,
+}
+
+impl Default for PhysicsGizmos {
+    fn default() -> Self {
+        Self {
+            rays_enabled: true,
+            ray_color: None,
+            hit_points_enabled: true,
+            hit_point_color: None,
+            hit_point_radius: 5.0,
+            normals_enabled: true,
+            normal_color: None,
+            normal_length: 30.0,
+        }
+    }
+}
+
+/// A plugin that draws debug visuals for physics, such as the rays, hit points, and
+/// surface normals of [`RayCaster`]s.
+///
+/// The visuals are styled and toggled per-category through the [`PhysicsGizmos`]
+/// gizmo config group, rather than requiring a hand-rolled rendering system like the
+/// `render_rays` example system.
+#[derive(Clone, Debug, Default)]
+pub struct PhysicsDebugPlugin;
+
+impl Plugin for PhysicsDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_gizmo_group::<PhysicsGizmos>()
+            .add_systems(PostUpdate, draw_ray_caster_gizmos);
+    }
+}
+
+fn draw_ray_caster_gizmos(
+    rays: Query<(&RayCaster, &RayHits)>,
+    mut gizmos: Gizmos<PhysicsGizmos>,
+    config_store: Res<GizmoConfigStore>,
+) {
+    let (_, physics_gizmos) = config_store.config::<PhysicsGizmos>();
+
+    for (ray, hits) in &rays {
+        let origin = ray.global_origin().f32();
+        let direction = ray.global_direction().f32();
+
+        for hit in hits.iter() {
+            let point = hit.point.f32();
+
+            if physics_gizmos.rays_enabled {
+                gizmos.line_2d(origin, point, physics_gizmos.ray_color.unwrap_or(Color::GREEN));
+            }
+            if physics_gizmos.hit_points_enabled {
+                gizmos.circle_2d(
+                    point,
+                    physics_gizmos.hit_point_radius,
+                    physics_gizmos.hit_point_color.unwrap_or(Color::YELLOW),
+                );
+            }
+            if physics_gizmos.normals_enabled {
+                gizmos.line_2d(
+                    point,
+                    point + hit.normal.f32() * physics_gizmos.normal_length,
+                    physics_gizmos.normal_color.unwrap_or(Color::CYAN),
+                );
+            }
+        }
+
+        if hits.is_empty() && physics_gizmos.rays_enabled {
+            gizmos.line_2d(
+                origin,
+                origin + direction * 1_000_000.0,
+                physics_gizmos.ray_color.unwrap_or(Color::ORANGE_RED),
+            );
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Plugins for adding physics functionality to a Bevy `App`.
+
+mod debug;
+
+pub use debug::{PhysicsDebugPlugin, PhysicsGizmos};
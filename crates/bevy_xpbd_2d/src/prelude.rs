@@ -0,0 +1,5 @@
+//! Re-exports of the most commonly used types and traits in `bevy_xpbd_2d`.
+
+pub use crate::components::{ApproximatedPrimitive, AngularVelocity, Collider, RigidBody};
+pub use crate::plugins::{PhysicsDebugPlugin, PhysicsGizmos};
+pub use crate::spatial_query::*;
@@ -0,0 +1,19 @@
+use bevy::{prelude::*, utils::HashSet};
+
+/// Determines which entities are considered by a spatial query such as
+/// [`SpatialQuery::cast_ray`](crate::prelude::SpatialQuery::cast_ray).
+///
+/// By default, a filter does not exclude anything.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpatialQueryFilter {
+    /// Entities that should be ignored by the query.
+    pub excluded_entities: HashSet<Entity>,
+}
+
+impl SpatialQueryFilter {
+    /// Creates a new [`SpatialQueryFilter`] that excludes the given set of entities.
+    pub fn with_excluded_entities(mut self, entities: impl IntoIterator<Item = Entity>) -> Self {
+        self.excluded_entities = entities.into_iter().collect();
+        self
+    }
+}
@@ -0,0 +1,348 @@
+//! Low-level ray/shape-vs-collider intersection tests used by [`SpatialQuery`](super::SpatialQuery).
+
+use bevy::math::bounding::Aabb2d;
+
+use crate::{components::ColliderShape, math::*};
+
+/// Returns the axis-aligned bounding box of a collider shape centered at
+/// `shape_position`, used by [`SpatialQuery`](super::SpatialQuery)'s broad-phase
+/// overlap queries.
+pub(crate) fn shape_aabb(shape: &ColliderShape, shape_position: Vector) -> Aabb2d {
+    let half_size = match shape {
+        ColliderShape::Circle { radius } => Vector::splat(*radius),
+        ColliderShape::Rectangle { half_extents } => *half_extents,
+    };
+    Aabb2d::new(shape_position.f32(), half_size.f32())
+}
+
+/// Checks whether two collider shapes, assumed to already pass an AABB overlap test,
+/// truly overlap.
+///
+/// Rectangle-vs-rectangle already matches the AABB test performed beforehand, since
+/// rectangle colliders do not yet support rotation. Circle-vs-circle and
+/// circle-vs-rectangle are refined exactly, since two AABBs can overlap near a corner
+/// without the underlying circle actually touching the other shape.
+pub(crate) fn shapes_overlap_exact(
+    shape_a: &ColliderShape,
+    position_a: Vector,
+    shape_b: &ColliderShape,
+    position_b: Vector,
+) -> bool {
+    match (shape_a, shape_b) {
+        (ColliderShape::Circle { radius: a }, ColliderShape::Circle { radius: b }) => {
+            (position_a - position_b).length() <= a + b
+        }
+        (ColliderShape::Circle { radius }, ColliderShape::Rectangle { half_extents }) => {
+            circle_vs_rectangle_overlap(position_a, *radius, position_b, *half_extents)
+        }
+        (ColliderShape::Rectangle { half_extents }, ColliderShape::Circle { radius }) => {
+            circle_vs_rectangle_overlap(position_b, *radius, position_a, *half_extents)
+        }
+        (ColliderShape::Rectangle { .. }, ColliderShape::Rectangle { .. }) => true,
+    }
+}
+
+/// Checks whether a circle truly overlaps an axis-aligned rectangle, by clamping the
+/// circle's center to the rectangle and comparing the distance to that closest point
+/// against the radius.
+fn circle_vs_rectangle_overlap(
+    circle_position: Vector,
+    radius: Scalar,
+    rectangle_position: Vector,
+    half_extents: Vector,
+) -> bool {
+    let min = rectangle_position - half_extents;
+    let max = rectangle_position + half_extents;
+    let closest_point = circle_position.clamp(min, max);
+    (circle_position - closest_point).length() <= radius
+}
+
+/// Checks whether `point` lies within a collider shape centered at `shape_position`.
+pub(crate) fn point_in_shape(shape: &ColliderShape, shape_position: Vector, point: Vector) -> bool {
+    match shape {
+        ColliderShape::Circle { radius } => (point - shape_position).length() <= *radius,
+        ColliderShape::Rectangle { half_extents } => {
+            let min = shape_position - half_extents;
+            let max = shape_position + half_extents;
+            point.x >= min.x && point.x <= max.x && point.y >= min.y && point.y <= max.y
+        }
+    }
+}
+
+/// Casts a ray against a single collider shape centered at `shape_position`, returning
+/// the time of impact and surface normal of the closest hit, if any.
+pub(crate) fn ray_vs_shape(
+    origin: Vector,
+    direction: Vector,
+    max_distance: Scalar,
+    solid: bool,
+    shape_position: Vector,
+    shape: &ColliderShape,
+) -> Option<(Scalar, Vector)> {
+    match shape {
+        ColliderShape::Circle { radius } => {
+            ray_vs_circle(origin, direction, max_distance, solid, shape_position, *radius)
+        }
+        ColliderShape::Rectangle { half_extents } => ray_vs_aabb(
+            origin,
+            direction,
+            max_distance,
+            solid,
+            shape_position,
+            *half_extents,
+        ),
+    }
+}
+
+/// Casts a ray against an axis-aligned box, returning the time of impact and surface
+/// normal of the closest hit, if any, using the slab method.
+///
+/// If `solid` is `true` and the ray starts inside the box, the hit is reported at a
+/// time of impact of zero. If `solid` is `false` and the ray starts inside the box,
+/// the *exit* point is reported instead, so that a ray already inside a transmissive
+/// shape can still find the far boundary it refracts out through.
+pub(crate) fn ray_vs_aabb(
+    origin: Vector,
+    direction: Vector,
+    max_distance: Scalar,
+    solid: bool,
+    center: Vector,
+    half_extents: Vector,
+) -> Option<(Scalar, Vector)> {
+    let min = center - half_extents;
+    let max = center + half_extents;
+
+    let inv_dir_x = if direction.x != 0.0 {
+        1.0 / direction.x
+    } else {
+        Scalar::INFINITY
+    };
+    let inv_dir_y = if direction.y != 0.0 {
+        1.0 / direction.y
+    } else {
+        Scalar::INFINITY
+    };
+
+    let (mut t_min, mut t_max) = ((min.x - origin.x) * inv_dir_x, (max.x - origin.x) * inv_dir_x);
+    if t_min > t_max {
+        std::mem::swap(&mut t_min, &mut t_max);
+    }
+
+    let (mut ty_min, mut ty_max) =
+        ((min.y - origin.y) * inv_dir_y, (max.y - origin.y) * inv_dir_y);
+    if ty_min > ty_max {
+        std::mem::swap(&mut ty_min, &mut ty_max);
+    }
+
+    if t_min > ty_max || ty_min > t_max {
+        return None;
+    }
+
+    let entering_axis_is_x = ty_min <= t_min;
+    if ty_min > t_min {
+        t_min = ty_min;
+    }
+    let exiting_axis_is_x = ty_max >= t_max;
+    if ty_max < t_max {
+        t_max = ty_max;
+    }
+
+    let inside = origin.x > min.x && origin.x < max.x && origin.y > min.y && origin.y < max.y;
+
+    if inside && solid {
+        return Some((0.0, (origin - center).normalize_or_zero()));
+    }
+
+    // From inside, `t_min` lies behind the ray; `t_max` is the exit point instead.
+    let (toi, axis_is_x) = if inside {
+        (t_max, exiting_axis_is_x)
+    } else {
+        (t_min, entering_axis_is_x)
+    };
+    if toi < 0.0 || toi > max_distance {
+        return None;
+    }
+
+    let point = origin + direction * toi;
+    let normal = if axis_is_x {
+        Vector::new((point.x - center.x).signum(), 0.0)
+    } else {
+        Vector::new(0.0, (point.y - center.y).signum())
+    };
+
+    Some((toi, normal))
+}
+
+/// Casts a ray against a circle, returning the time of impact and surface normal of
+/// the closest hit, if any.
+///
+/// If `solid` is `true` and the ray starts inside the circle, the hit is reported at
+/// a time of impact of zero. If `solid` is `false` and the ray starts inside the
+/// circle, the *exit* point is reported instead, so that a ray already inside a
+/// transmissive shape can still find the far boundary it refracts out through.
+pub(crate) fn ray_vs_circle(
+    origin: Vector,
+    direction: Vector,
+    max_distance: Scalar,
+    solid: bool,
+    center: Vector,
+    radius: Scalar,
+) -> Option<(Scalar, Vector)> {
+    let offset = origin - center;
+    let b = offset.dot(direction);
+    let c = offset.length_squared() - radius * radius;
+    let inside = c < 0.0;
+
+    if inside && solid {
+        return Some((0.0, offset.normalize_or_zero()));
+    }
+
+    let discriminant = b * b - c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let sqrt_discriminant = discriminant.sqrt();
+
+    // From inside, the near root `-b - sqrt(disc)` lies behind the ray; the far root
+    // is the exit point instead.
+    let toi = if inside {
+        -b + sqrt_discriminant
+    } else {
+        -b - sqrt_discriminant
+    };
+    if toi < 0.0 || toi > max_distance {
+        return None;
+    }
+
+    let point = origin + direction * toi;
+    let normal = (point - center).normalize_or_zero();
+    Some((toi, normal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ray_vs_circle_hits_from_outside() {
+        let hit = ray_vs_circle(
+            Vector::new(-10.0, 0.0),
+            Vector::X,
+            100.0,
+            false,
+            Vector::ZERO,
+            1.0,
+        );
+        let (toi, normal) = hit.expect("ray should hit the circle");
+        assert!((toi - 9.0).abs() < 1e-5);
+        assert!(normal.distance(Vector::NEG_X) < 1e-5);
+    }
+
+    #[test]
+    fn ray_vs_circle_misses() {
+        let hit = ray_vs_circle(
+            Vector::new(-10.0, 5.0),
+            Vector::X,
+            100.0,
+            false,
+            Vector::ZERO,
+            1.0,
+        );
+        assert!(hit.is_none());
+    }
+
+    #[test]
+    fn ray_vs_circle_solid_reports_zero_toi_from_inside() {
+        let hit = ray_vs_circle(Vector::ZERO, Vector::X, 100.0, true, Vector::ZERO, 1.0);
+        let (toi, _) = hit.expect("a solid circle should report a hit from inside");
+        assert_eq!(toi, 0.0);
+    }
+
+    #[test]
+    fn ray_vs_circle_non_solid_finds_exit_point_from_inside() {
+        let hit = ray_vs_circle(Vector::ZERO, Vector::X, 100.0, false, Vector::ZERO, 1.0);
+        let (toi, normal) = hit.expect("a non-solid circle should report its exit point");
+        assert!((toi - 1.0).abs() < 1e-5);
+        assert!(normal.distance(Vector::X) < 1e-5);
+    }
+
+    #[test]
+    fn ray_vs_aabb_hits_from_outside() {
+        let hit = ray_vs_aabb(
+            Vector::new(-10.0, 0.0),
+            Vector::X,
+            100.0,
+            false,
+            Vector::ZERO,
+            Vector::splat(1.0),
+        );
+        let (toi, normal) = hit.expect("ray should hit the box");
+        assert!((toi - 9.0).abs() < 1e-5);
+        assert!(normal.distance(Vector::NEG_X) < 1e-5);
+    }
+
+    #[test]
+    fn ray_vs_aabb_solid_reports_zero_toi_from_inside() {
+        let hit = ray_vs_aabb(
+            Vector::ZERO,
+            Vector::X,
+            100.0,
+            true,
+            Vector::ZERO,
+            Vector::splat(1.0),
+        );
+        let (toi, _) = hit.expect("a solid box should report a hit from inside");
+        assert_eq!(toi, 0.0);
+    }
+
+    #[test]
+    fn ray_vs_aabb_non_solid_finds_exit_point_from_inside() {
+        let hit = ray_vs_aabb(
+            Vector::ZERO,
+            Vector::X,
+            100.0,
+            false,
+            Vector::ZERO,
+            Vector::splat(1.0),
+        );
+        let (toi, normal) = hit.expect("a non-solid box should report its exit point");
+        assert!((toi - 1.0).abs() < 1e-5);
+        assert!(normal.distance(Vector::X) < 1e-5);
+    }
+
+    #[test]
+    fn circle_vs_rectangle_overlap_detects_corner_gap() {
+        // The AABBs of these two shapes touch near a shared corner, but the circle
+        // itself never reaches the rectangle.
+        assert!(!circle_vs_rectangle_overlap(
+            Vector::new(10.0, 10.0),
+            1.0,
+            Vector::new(12.0, 12.0),
+            Vector::splat(1.0),
+        ));
+    }
+
+    #[test]
+    fn circle_vs_rectangle_overlap_detects_true_overlap() {
+        assert!(circle_vs_rectangle_overlap(
+            Vector::new(10.0, 10.0),
+            1.5,
+            Vector::new(12.0, 12.0),
+            Vector::splat(1.0),
+        ));
+    }
+
+    #[test]
+    fn point_in_shape_circle() {
+        assert!(point_in_shape(
+            &ColliderShape::Circle { radius: 1.0 },
+            Vector::new(5.0, 5.0),
+            Vector::new(5.5, 5.0),
+        ));
+        assert!(!point_in_shape(
+            &ColliderShape::Circle { radius: 1.0 },
+            Vector::new(5.0, 5.0),
+            Vector::new(7.0, 5.0),
+        ));
+    }
+}
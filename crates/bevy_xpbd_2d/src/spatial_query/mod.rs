@@ -0,0 +1,18 @@
+//! Spatial queries, like casting rays and shapes against the colliders in the world.
+//!
+//! The [`SpatialQuery`] system parameter is the main entry point for one-off queries.
+//! For a ray caster that lives in the world and casts every physics frame, see
+//! [`RayCaster`].
+
+mod filter;
+mod intersection;
+mod optics;
+mod ray_caster;
+mod shape_caster;
+mod system_param;
+
+pub use filter::SpatialQueryFilter;
+pub use optics::{OpticalRaySegment, RayOpticsMaterial};
+pub use ray_caster::{RayCaster, RayHitData, RayHits};
+pub use shape_caster::{ShapeCaster, ShapeHitData, ShapeHits};
+pub use system_param::SpatialQuery;
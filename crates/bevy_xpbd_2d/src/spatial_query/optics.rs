@@ -0,0 +1,108 @@
+use bevy::prelude::*;
+
+use crate::math::*;
+
+/// An optional collider component that determines how a collider interacts with
+/// [`SpatialQuery::trace_optical_ray`](super::SpatialQuery::trace_optical_ray).
+///
+/// Colliders without this component are treated as opaque: a traced ray simply stops
+/// when it hits one, the same as hitting any other surface.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub enum RayOpticsMaterial {
+    /// The surface mirrors the ray: `r = d - 2(d·n)n`, where `d` is the incoming unit
+    /// direction and `n` is the unit surface normal.
+    Reflective,
+    /// The surface refracts the ray according to Snell's law, using `index_of_refraction`
+    /// as the relative index of refraction `η = η_in / η_out` between the medium the
+    /// ray is entering and the one it is leaving. If the angle of incidence exceeds
+    /// the critical angle, the ray undergoes total internal reflection and mirrors
+    /// instead.
+    Transmissive {
+        /// The relative index of refraction `η = η_in / η_out`.
+        index_of_refraction: Scalar,
+    },
+    /// The surface absorbs the ray using Beer-Lambert attenuation: when the ray hits
+    /// it, the carried intensity is multiplied by `exp(-absorption * segment_length)`,
+    /// where `segment_length` is the length of the segment that ended at the hit. The
+    /// ray is otherwise left undeviated, and the same collider is not hit again.
+    Absorptive {
+        /// The absorption coefficient of the material.
+        absorption: Scalar,
+    },
+}
+
+/// A single segment of the path traced by
+/// [`SpatialQuery::trace_optical_ray`](super::SpatialQuery::trace_optical_ray).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OpticalRaySegment {
+    /// The hit that ended this segment.
+    pub hit: super::RayHitData,
+    /// The intensity carried by the ray at the *start* of this segment, before the
+    /// hit surface's material, if any, has been applied.
+    pub intensity: Scalar,
+}
+
+/// Returns the mirror-reflection of `direction` off of a surface with the given unit
+/// `normal`: `r = d - 2(d·n)n`.
+pub(crate) fn reflect(direction: Vector, normal: Vector) -> Vector {
+    (direction - 2.0 * direction.dot(normal) * normal).normalize_or_zero()
+}
+
+/// Returns the Snell's-law refraction of `direction` through a surface with the given
+/// unit `normal` and relative index of refraction `eta = η_in / η_out`, along with
+/// whether the ray actually crossed the surface.
+///
+/// Falls back to [`reflect`] under total internal reflection, in which case the
+/// returned `bool` is `false`: the ray stayed on the same side of the surface instead
+/// of transmitting through it.
+pub(crate) fn refract(direction: Vector, normal: Vector, eta: Scalar) -> (Vector, bool) {
+    let cos_theta = -direction.dot(normal);
+    let k = 1.0 - eta * eta * (1.0 - cos_theta * cos_theta);
+
+    if k < 0.0 {
+        (reflect(direction, normal), false)
+    } else {
+        (
+            (eta * direction + (eta * cos_theta - k.sqrt()) * normal).normalize_or_zero(),
+            true,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reflect_head_on_reverses_direction() {
+        let reflected = reflect(Vector::X, Vector::NEG_X);
+        assert!(reflected.distance(Vector::NEG_X) < 1e-5);
+    }
+
+    #[test]
+    fn reflect_glancing_hit_preserves_tangential_component() {
+        // A ray travelling along a wall's surface (normal perpendicular to it) should
+        // pass through undeviated.
+        let reflected = reflect(Vector::X, Vector::Y);
+        assert!(reflected.distance(Vector::X) < 1e-5);
+    }
+
+    #[test]
+    fn refract_straight_through_at_matching_index() {
+        let (refracted, transmitted) = refract(Vector::X, Vector::NEG_X, 1.0);
+        assert!(refracted.distance(Vector::X) < 1e-5);
+        assert!(transmitted);
+    }
+
+    #[test]
+    fn refract_falls_back_to_reflection_under_total_internal_reflection() {
+        // A steep glancing ray exiting a denser medium (eta > 1) at a shallow angle
+        // exceeds the critical angle and should mirror instead of refracting.
+        let direction = Vector::new(0.141, 0.99).normalize();
+        let normal = Vector::NEG_X;
+        let (refracted, transmitted) = refract(direction, normal, 2.0);
+        let reflected = reflect(direction, normal);
+        assert!(refracted.distance(reflected) < 1e-5);
+        assert!(!transmitted);
+    }
+}
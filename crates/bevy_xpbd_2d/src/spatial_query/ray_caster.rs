@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+
+use crate::math::*;
+
+/// A component that casts a ray and stores the hits in a [`RayHits`] component every
+/// physics frame.
+///
+/// For a one-off raycast that does not need to be attached to an entity, use the
+/// [`SpatialQuery`](crate::prelude::SpatialQuery) system parameter instead.
+#[derive(Component, Clone, Debug)]
+pub struct RayCaster {
+    /// The origin of the ray relative to the entity's [`Transform`].
+    pub origin: Vector,
+    /// The direction of the ray relative to the entity's [`Transform`].
+    pub direction: Direction2d,
+    /// The global origin of the ray, updated automatically before each physics step.
+    global_origin: Vector,
+    /// The global direction of the ray, updated automatically before each physics step.
+    global_direction: Direction2d,
+}
+
+impl RayCaster {
+    /// Creates a new [`RayCaster`] with the given origin and direction.
+    pub fn new(origin: Vector, direction: Direction2d) -> Self {
+        Self {
+            origin,
+            direction,
+            global_origin: origin,
+            global_direction: direction,
+        }
+    }
+
+    /// The global origin of the ray, taking into account the entity's [`Transform`].
+    pub fn global_origin(&self) -> Vector {
+        self.global_origin
+    }
+
+    /// The global direction of the ray, taking into account the entity's [`Transform`].
+    pub fn global_direction(&self) -> Direction2d {
+        self.global_direction
+    }
+}
+
+/// Contains the hits of a [`RayCaster`], sorted by distance.
+#[derive(Component, Clone, Debug, Default)]
+pub struct RayHits {
+    pub(crate) hits: Vec<RayHitData>,
+}
+
+impl RayHits {
+    /// Returns an iterator over the hits, sorted by distance.
+    pub fn iter(&self) -> impl Iterator<Item = &RayHitData> {
+        self.hits.iter()
+    }
+
+    /// Returns `true` if no hits were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.hits.is_empty()
+    }
+}
+
+/// Data related to a hit during a ray cast.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHitData {
+    /// The entity that was hit by the ray.
+    pub entity: Entity,
+    /// How far the ray travelled before hitting something, in the direction of the
+    /// ray.
+    pub time_of_impact: Scalar,
+    /// The point of impact in world space.
+    pub point: Vector,
+    /// The normal of the surface that was hit, at the point of impact.
+    pub normal: Vector,
+}
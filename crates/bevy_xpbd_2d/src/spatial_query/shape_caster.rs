@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+
+use crate::{math::*, prelude::*};
+
+/// A component that sweeps a [`Collider`] along a direction and stores the hits in a
+/// [`ShapeHits`] component every physics frame.
+///
+/// For a one-off shape cast that does not need to be attached to an entity, use the
+/// [`SpatialQuery`](crate::prelude::SpatialQuery) system parameter instead.
+#[derive(Component, Clone, Debug)]
+pub struct ShapeCaster {
+    /// The shape being swept.
+    pub shape: Collider,
+    /// The origin of the cast relative to the entity's [`Transform`].
+    pub origin: Vector,
+    /// The rotation of the cast shape, in radians.
+    pub rotation: Scalar,
+    /// The direction the shape is swept in, relative to the entity's [`Transform`].
+    pub direction: Direction2d,
+}
+
+impl ShapeCaster {
+    /// Creates a new [`ShapeCaster`] that sweeps the given [`Collider`].
+    pub fn new(shape: Collider, origin: Vector, rotation: Scalar, direction: Direction2d) -> Self {
+        Self {
+            shape,
+            origin,
+            rotation,
+            direction,
+        }
+    }
+
+    /// Creates a new [`ShapeCaster`] from a geometric primitive, such as
+    /// [`Circle`](bevy::math::primitives::Circle) or
+    /// [`Rectangle`](bevy::math::primitives::Rectangle), instead of an
+    /// already-built [`Collider`].
+    pub fn from_primitive(
+        primitive: impl Into<Collider>,
+        origin: Vector,
+        rotation: Scalar,
+        direction: Direction2d,
+    ) -> Self {
+        Self::new(primitive.into(), origin, rotation, direction)
+    }
+}
+
+/// Contains the hits of a [`ShapeCaster`], sorted by distance.
+#[derive(Component, Clone, Debug, Default)]
+pub struct ShapeHits {
+    pub(crate) hits: Vec<ShapeHitData>,
+}
+
+impl ShapeHits {
+    /// Returns an iterator over the hits, sorted by distance.
+    pub fn iter(&self) -> impl Iterator<Item = &ShapeHitData> {
+        self.hits.iter()
+    }
+
+    /// Returns `true` if no hits were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.hits.is_empty()
+    }
+}
+
+/// Data related to a hit during a shape cast.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShapeHitData {
+    /// The entity that was hit by the shape.
+    pub entity: Entity,
+    /// How far the shape travelled before hitting something, in the direction of the
+    /// cast.
+    pub time_of_impact: Scalar,
+    /// The point of impact in world space.
+    pub point: Vector,
+    /// The normal of the surface that was hit, at the point of impact.
+    pub normal: Vector,
+}
@@ -0,0 +1,609 @@
+use bevy::{
+    ecs::system::SystemParam,
+    math::bounding::{Aabb2d, IntersectsVolume},
+    prelude::*,
+};
+
+use super::{
+    intersection::{point_in_shape, ray_vs_shape, shape_aabb, shapes_overlap_exact},
+    optics::{reflect, refract},
+    OpticalRaySegment, RayOpticsMaterial, ShapeHitData,
+};
+use crate::{math::*, prelude::*};
+
+/// How far a bounced or refracted ray segment starts past the surface it left, along
+/// its new direction, so that it does not immediately re-detect that same surface as
+/// a zero-distance hit.
+const BOUNCE_EPSILON: Scalar = 1e-4;
+
+/// A [`SystemParam`] for performing spatial queries, such as casting rays and shapes,
+/// against the colliders in the world.
+///
+/// For a ray caster that lives in the world and casts every physics frame instead of
+/// being called on demand, see [`RayCaster`].
+#[derive(SystemParam)]
+pub struct SpatialQuery<'w, 's> {
+    colliders: Query<'w, 's, (Entity, &'static GlobalTransform, &'static Collider)>,
+    optics_materials: Query<'w, 's, &'static RayOpticsMaterial>,
+}
+
+impl<'w, 's> SpatialQuery<'w, 's> {
+    /// Casts a ray and returns the closest hit, if any.
+    ///
+    /// * `origin`: Where the ray starts.
+    /// * `direction`: The direction the ray is cast in.
+    /// * `max_distance`: The maximum distance the ray can travel.
+    /// * `solid`: If `true`, the ray treats colliders as solid, so it will return a
+    ///   hit at a time of impact of zero if it starts inside a collider. If `false`,
+    ///   a ray starting inside of a collider hits that collider where it exits it.
+    /// * `filter`: Determines which entities are excluded from the query.
+    pub fn cast_ray(
+        &self,
+        origin: Vector,
+        direction: Direction2d,
+        max_distance: Scalar,
+        solid: bool,
+        filter: SpatialQueryFilter,
+    ) -> Option<RayHitData> {
+        self.colliders
+            .iter()
+            .filter(|(entity, ..)| !filter.excluded_entities.contains(entity))
+            .filter_map(|(entity, transform, collider)| {
+                ray_vs_shape(
+                    origin,
+                    *direction,
+                    max_distance,
+                    solid,
+                    transform.translation().truncate(),
+                    &collider.shape,
+                )
+                .map(|(time_of_impact, normal)| RayHitData {
+                    entity,
+                    time_of_impact,
+                    point: origin + *direction * time_of_impact,
+                    normal,
+                })
+            })
+            .min_by(|a, b| a.time_of_impact.total_cmp(&b.time_of_impact))
+    }
+
+    /// Casts a ray like [`cast_ray`](Self::cast_ray), but follows it through up to
+    /// `max_bounces` specular reflections off of the surfaces it hits, returning the
+    /// ordered path of hits.
+    ///
+    /// At each hit, the next segment starts at the hit point and travels along the
+    /// mirror-reflection direction `r = d - 2(d·n)n`, where `d` is the incoming unit
+    /// direction and `n` is the unit surface normal. To avoid the new segment
+    /// immediately re-detecting the surface it just left as a zero-distance hit, it
+    /// starts offset a small epsilon past the hit point along `r`, rather than
+    /// permanently excluding the hit entity — so the path can still bounce off the
+    /// same collider again later, as it would off two walls of a box.
+    ///
+    /// The walk stops once `max_bounces` reflections have occurred, a segment finds
+    /// no hit, or the remaining distance budget (`max_distance`, shared across the
+    /// whole path) is exhausted.
+    pub fn cast_ray_reflected(
+        &self,
+        origin: Vector,
+        direction: Direction2d,
+        max_distance: Scalar,
+        max_bounces: u32,
+        solid: bool,
+        filter: SpatialQueryFilter,
+    ) -> Vec<RayHitData> {
+        let mut hits = Vec::new();
+        let mut current_origin = origin;
+        let mut current_direction = *direction;
+        let mut remaining_distance = max_distance;
+
+        for _ in 0..=max_bounces {
+            let Some(hit) = self.cast_ray(
+                current_origin,
+                Direction2d::new_unchecked(current_direction),
+                remaining_distance,
+                solid,
+                filter.clone(),
+            ) else {
+                break;
+            };
+
+            remaining_distance -= hit.time_of_impact;
+            current_direction = reflect(current_direction, hit.normal);
+            current_origin = hit.point + current_direction * BOUNCE_EPSILON;
+
+            hits.push(hit);
+
+            if remaining_distance <= 0.0 || current_direction == Vector::ZERO {
+                break;
+            }
+        }
+
+        hits
+    }
+
+    /// Picks the collider under a camera's viewport cursor position, if any.
+    ///
+    /// This mirrors the screen-space raycast workflow popularized by crates like
+    /// `bevy_mod_raycast` (cursor -> world position -> hit), but unlike the 3D
+    /// analogue a 2D scene has no depth along the camera's view axis to cast a ray
+    /// through. The cursor position is unprojected into the world using the
+    /// camera's [`GlobalTransform`] and projection, and the resulting point is then
+    /// used for a point-in-collider test rather than a ray cast.
+    ///
+    /// Consequently only `solid: true` is meaningful here — it is the only way a
+    /// point can be "inside" a collider at all — so this returns `None` immediately
+    /// if `solid` is `false`. `max_distance` is accepted for API symmetry with
+    /// [`cast_ray`](Self::cast_ray) but is otherwise unused.
+    ///
+    /// Returns `None` if the cursor lies outside the camera's viewport or is not
+    /// over any collider.
+    pub fn cast_ray_from_cursor(
+        &self,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+        cursor_position: bevy::math::Vec2,
+        max_distance: Scalar,
+        solid: bool,
+        filter: SpatialQueryFilter,
+    ) -> Option<RayHitData> {
+        let _ = max_distance;
+        if !solid {
+            return None;
+        }
+
+        let viewport_size = camera.logical_viewport_size()?;
+        if cursor_position.cmplt(bevy::math::Vec2::ZERO).any()
+            || cursor_position.cmpgt(viewport_size).any()
+        {
+            return None;
+        }
+
+        let world_position = camera.viewport_to_world_2d(camera_transform, cursor_position)?;
+        let point = world_position.adjust_precision();
+
+        self.colliders
+            .iter()
+            .filter(|(entity, ..)| !filter.excluded_entities.contains(entity))
+            .find(|(_, transform, collider)| {
+                point_in_shape(&collider.shape, transform.translation().truncate(), point)
+            })
+            .map(|(entity, transform, _)| RayHitData {
+                entity,
+                time_of_impact: 0.0,
+                point,
+                normal: (point - transform.translation().truncate()).normalize_or_zero(),
+            })
+    }
+
+    /// Traces a ray through the world as if it were a beam of light, following
+    /// reflection and refraction at colliders with a [`RayOpticsMaterial`].
+    ///
+    /// At each hit, the behavior depends on the material of the entity that was hit:
+    ///
+    /// * [`Reflective`](RayOpticsMaterial::Reflective) mirrors the ray.
+    /// * [`Transmissive`](RayOpticsMaterial::Transmissive) refracts the ray using
+    ///   Snell's law, falling back to a mirror reflection under total internal
+    ///   reflection.
+    /// * [`Absorptive`](RayOpticsMaterial::Absorptive) attenuates the carried
+    ///   intensity using Beer-Lambert absorption and lets the ray continue
+    ///   undeviated.
+    /// * Colliders with no [`RayOpticsMaterial`] are treated as opaque and stop the
+    ///   trace.
+    ///
+    /// A [`Reflective`](RayOpticsMaterial::Reflective) hit is never excluded from
+    /// later segments (only epsilon-offset, like
+    /// [`cast_ray_reflected`](Self::cast_ray_reflected)), so a mirror can be hit
+    /// again later in the path. An [`Absorptive`](RayOpticsMaterial::Absorptive) hit
+    /// *is* excluded from later segments, since it does not deflect the ray and the
+    /// trace would otherwise just keep re-reporting a zero-distance hit against the
+    /// same collider. A [`Transmissive`](RayOpticsMaterial::Transmissive) hit is
+    /// excluded from nothing, but while the ray is inside a transmissive collider its
+    /// segments are cast with `solid: false` so that, starting just inside the
+    /// collider the ray entered, it finds that same collider's far boundary instead of an immediate zero-distance
+    /// hit against itself — and refracts again there, the way light bends both
+    /// entering and leaving glass.
+    ///
+    /// The ray starts with an intensity of `1.0`. The trace stops once `max_bounces`
+    /// further hits have followed the first one (at most `max_bounces + 1` hits in
+    /// all), the distance budget `max_distance` is exhausted, the
+    /// intensity drops below `min_intensity`, or a segment finds no hit.
+    pub fn trace_optical_ray(
+        &self,
+        origin: Vector,
+        direction: Direction2d,
+        max_distance: Scalar,
+        max_bounces: u32,
+        min_intensity: Scalar,
+        filter: SpatialQueryFilter,
+    ) -> Vec<OpticalRaySegment> {
+        let mut path = Vec::new();
+        let mut current_origin = origin;
+        let mut current_direction = *direction;
+        let mut remaining_distance = max_distance;
+        let mut intensity = 1.0;
+        let mut excluded_entities = filter.excluded_entities.clone();
+        // The transmissive entity the ray is currently inside of, if any, so its exit
+        // hit can be told apart from a fresh entry into some other transmissive entity.
+        let mut entered_transmissive_entity = None;
+
+        for _ in 0..=max_bounces {
+            let solid = entered_transmissive_entity.is_none();
+            let segment_filter = SpatialQueryFilter {
+                excluded_entities: excluded_entities.clone(),
+            };
+            let Some(hit) = self.cast_ray(
+                current_origin,
+                Direction2d::new_unchecked(current_direction),
+                remaining_distance,
+                solid,
+                segment_filter,
+            ) else {
+                break;
+            };
+
+            remaining_distance -= hit.time_of_impact;
+
+            path.push(OpticalRaySegment { hit, intensity });
+
+            match self.optics_materials.get(hit.entity) {
+                Ok(RayOpticsMaterial::Reflective) => {
+                    current_direction = reflect(current_direction, hit.normal);
+                }
+                Ok(RayOpticsMaterial::Transmissive {
+                    index_of_refraction,
+                }) => {
+                    // `refract` takes the index of the medium the ray leaves over that
+                    // of the medium it enters, and expects `normal` to face against the
+                    // incoming ray, as it does at entry, where the ratio is the inverse
+                    // of `index_of_refraction`. At an exit boundary the segment was cast
+                    // with `solid: false`, so `hit.normal` instead faces *along* the ray
+                    // (`current_direction.dot(hit.normal) > 0`); flip the normal and use
+                    // `index_of_refraction` itself so the refraction is computed as
+                    // crossing from the inside medium back out, not as a second entry.
+                    let exiting = current_direction.dot(hit.normal) > 0.0;
+                    let (normal, eta) = if exiting {
+                        (-hit.normal, *index_of_refraction)
+                    } else {
+                        (hit.normal, 1.0 / *index_of_refraction)
+                    };
+                    let (refracted_direction, transmitted) =
+                        refract(current_direction, normal, eta);
+                    current_direction = refracted_direction;
+                    // Total internal reflection leaves the ray on the same side of the
+                    // surface it was already on, so only a real transmission flips
+                    // whether it is inside this entity.
+                    if transmitted {
+                        entered_transmissive_entity =
+                            if entered_transmissive_entity == Some(hit.entity) {
+                                None
+                            } else {
+                                Some(hit.entity)
+                            };
+                    }
+                }
+                Ok(RayOpticsMaterial::Absorptive { absorption }) => {
+                    intensity *= (-absorption * hit.time_of_impact).exp();
+                    excluded_entities.insert(hit.entity);
+                }
+                Err(_) => break,
+            }
+
+            current_origin = hit.point + current_direction * BOUNCE_EPSILON;
+
+            if remaining_distance <= 0.0 || intensity < min_intensity {
+                break;
+            }
+        }
+
+        path
+    }
+
+    /// Sweeps a [`Collider`] along `direction` and returns the closest hit, if any.
+    ///
+    /// Internally this casts a ray against every collider grown outward by the swept
+    /// shape's [bounding radius](crate::components::ColliderShape::bounding_radius),
+    /// the usual trick for turning a shape cast into a ray cast. As a consequence,
+    /// shape casts are only approximate: the swept shape is treated as its bounding
+    /// circle, and the grown corners of rectangle colliders are treated as square
+    /// rather than rounded. `rotation` is accepted for API symmetry with a future exact
+    /// implementation but is not yet applied.
+    pub fn cast_shape(
+        &self,
+        shape: &Collider,
+        origin: Vector,
+        _rotation: Scalar,
+        direction: Direction2d,
+        max_distance: Scalar,
+        solid: bool,
+        filter: SpatialQueryFilter,
+    ) -> Option<ShapeHitData> {
+        let caster_radius = shape.shape.bounding_radius();
+
+        self.colliders
+            .iter()
+            .filter(|(entity, ..)| !filter.excluded_entities.contains(entity))
+            .filter_map(|(entity, transform, collider)| {
+                let grown_shape = collider.shape.inflated(caster_radius);
+                ray_vs_shape(
+                    origin,
+                    *direction,
+                    max_distance,
+                    solid,
+                    transform.translation().truncate(),
+                    &grown_shape,
+                )
+                .map(|(time_of_impact, normal)| ShapeHitData {
+                    entity,
+                    time_of_impact,
+                    // The ray hits the grown shape at the swept shape's center; the
+                    // contact point lies `caster_radius` back along the normal.
+                    point: origin + *direction * time_of_impact - normal * caster_radius,
+                    normal,
+                })
+            })
+            .min_by(|a, b| a.time_of_impact.total_cmp(&b.time_of_impact))
+    }
+
+    /// Sweeps a geometric primitive, such as
+    /// [`Circle`](bevy::math::primitives::Circle) or
+    /// [`Rectangle`](bevy::math::primitives::Rectangle), along `direction` and
+    /// returns the closest hit, if any.
+    ///
+    /// This is [`cast_shape`](Self::cast_shape) with the primitive converted into a
+    /// [`Collider`] first, so that a collider does not have to be hand-built just to
+    /// sweep it.
+    pub fn cast_shape_primitive(
+        &self,
+        primitive: impl Into<Collider>,
+        origin: Vector,
+        rotation: Scalar,
+        direction: Direction2d,
+        max_distance: Scalar,
+        solid: bool,
+        filter: SpatialQueryFilter,
+    ) -> Option<ShapeHitData> {
+        self.cast_shape(
+            &primitive.into(),
+            origin,
+            rotation,
+            direction,
+            max_distance,
+            solid,
+            filter,
+        )
+    }
+
+    /// Returns every collider whose bounding box intersects `aabb`.
+    ///
+    /// Unlike [`cast_ray`](Self::cast_ray) or [`cast_shape`](Self::cast_shape), this
+    /// answers "what is inside this region" queries in a single broad-phase pass
+    /// using cheap AABB-vs-AABB tests, making it a good fit for triggers, sensors,
+    /// and area-of-effect style lookups.
+    pub fn aabb_overlaps<'a>(
+        &'a self,
+        aabb: Aabb2d,
+        filter: SpatialQueryFilter,
+    ) -> impl Iterator<Item = Entity> + 'a {
+        self.colliders
+            .iter()
+            .filter(move |(entity, ..)| !filter.excluded_entities.contains(entity))
+            .filter(move |(_, transform, collider)| {
+                shape_aabb(&collider.shape, transform.translation().truncate()).intersects(&aabb)
+            })
+            .map(|(entity, ..)| entity)
+    }
+
+    /// Returns every collider that overlaps the given [`Collider`] at `position`.
+    ///
+    /// Candidates are first filtered with a cheap AABB-vs-AABB test against the
+    /// broad-phase, and only refined to an exact shape overlap test afterwards,
+    /// following the same two-stage approach as [`aabb_overlaps`](Self::aabb_overlaps).
+    /// `rotation` is accepted for API symmetry with a future exact implementation but
+    /// is not yet applied.
+    pub fn shape_overlaps<'a>(
+        &'a self,
+        shape: &'a Collider,
+        position: Vector,
+        _rotation: Scalar,
+        filter: SpatialQueryFilter,
+    ) -> impl Iterator<Item = Entity> + 'a {
+        let query_aabb = shape_aabb(&shape.shape, position);
+
+        self.colliders
+            .iter()
+            .filter(move |(entity, ..)| !filter.excluded_entities.contains(entity))
+            .filter(move |(_, transform, collider)| {
+                let candidate_position = transform.translation().truncate();
+                shape_aabb(&collider.shape, candidate_position).intersects(&query_aabb)
+                    && shapes_overlap_exact(&shape.shape, position, &collider.shape, candidate_position)
+            })
+            .map(|(entity, ..)| entity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::SystemState;
+
+    use super::*;
+
+    #[test]
+    fn cast_ray_reflected_can_bounce_off_the_same_wall_twice() {
+        // Two vertical walls forming a box a ray ricochets inside of, the way the
+        // `ray_caster` example's perimeter of colliders works. A cumulative
+        // exclusion set would make the ray die after bouncing off each wall once;
+        // it should instead be free to re-hit a wall it already bounced off.
+        let mut world = World::new();
+        let left_wall = world
+            .spawn((
+                GlobalTransform::from_translation(Vec3::new(-10.0, 0.0, 0.0)),
+                Collider::rectangle(2.0, 20.0),
+            ))
+            .id();
+        let right_wall = world
+            .spawn((
+                GlobalTransform::from_translation(Vec3::new(10.0, 0.0, 0.0)),
+                Collider::rectangle(2.0, 20.0),
+            ))
+            .id();
+
+        let mut state = SystemState::<SpatialQuery>::new(&mut world);
+        let spatial_query = state.get(&world);
+
+        let hits = spatial_query.cast_ray_reflected(
+            Vector::ZERO,
+            Direction2d::X,
+            100.0,
+            2,
+            true,
+            SpatialQueryFilter::default(),
+        );
+
+        assert_eq!(
+            hits.len(),
+            3,
+            "expected the first hit plus 2 bounces: right, left, right again"
+        );
+        assert_eq!(hits[0].entity, right_wall);
+        assert_eq!(hits[1].entity, left_wall);
+        assert_eq!(
+            hits[2].entity, right_wall,
+            "the ray should be able to re-hit a wall it already bounced off"
+        );
+    }
+
+    #[test]
+    fn trace_optical_ray_attenuates_through_an_absorptive_collider_only_once() {
+        // An absorptive slab followed by an opaque wall further along the same ray.
+        // Allowing the ray to re-hit the same entity (needed so a *transmissive*
+        // collider can be hit again to find its exit) must not let it also re-hit an
+        // absorptive collider it doesn't deflect away from, or the ray, starting just
+        // inside the slab, keeps re-hitting it at zero distance and never reaches the
+        // wall.
+        let mut world = World::new();
+        let absorption = 0.1;
+        let slab = world
+            .spawn((
+                GlobalTransform::from_translation(Vec3::new(5.0, 0.0, 0.0)),
+                Collider::rectangle(2.0, 20.0),
+                RayOpticsMaterial::Absorptive { absorption },
+            ))
+            .id();
+        let wall = world
+            .spawn((
+                GlobalTransform::from_translation(Vec3::new(20.0, 0.0, 0.0)),
+                Collider::rectangle(2.0, 20.0),
+            ))
+            .id();
+
+        let mut state = SystemState::<SpatialQuery>::new(&mut world);
+        let spatial_query = state.get(&world);
+
+        let path = spatial_query.trace_optical_ray(
+            Vector::ZERO,
+            Direction2d::X,
+            100.0,
+            5,
+            0.0,
+            SpatialQueryFilter::default(),
+        );
+
+        assert_eq!(
+            path.len(),
+            2,
+            "the ray should pass through the slab's exit unnoticed, then stop at the opaque wall"
+        );
+        assert_eq!(path[0].hit.entity, slab);
+        assert_eq!(path[0].intensity, 1.0);
+        assert_eq!(path[1].hit.entity, wall);
+        assert!((path[1].intensity - (-absorption * 4.0 as Scalar).exp()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn trace_optical_ray_exits_a_transmissive_collider_head_on() {
+        // A head-on ray through a glass slab should refract back to travelling
+        // straight forward at the exit boundary, not reverse back into the slab.
+        let mut world = World::new();
+        let slab = world
+            .spawn((
+                GlobalTransform::from_translation(Vec3::new(5.0, 0.0, 0.0)),
+                Collider::rectangle(2.0, 20.0),
+                RayOpticsMaterial::Transmissive {
+                    index_of_refraction: 1.5,
+                },
+            ))
+            .id();
+
+        let mut state = SystemState::<SpatialQuery>::new(&mut world);
+        let spatial_query = state.get(&world);
+
+        let path = spatial_query.trace_optical_ray(
+            Vector::ZERO,
+            Direction2d::X,
+            100.0,
+            5,
+            0.0,
+            SpatialQueryFilter::default(),
+        );
+
+        assert_eq!(path.len(), 2, "expected an entry hit and an exit hit");
+        assert_eq!(path[0].hit.entity, slab);
+        assert_eq!(path[1].hit.entity, slab);
+        assert!(
+            path[1].hit.point.x > path[0].hit.point.x,
+            "the ray should keep travelling forward through the slab, not reverse \
+             back into it"
+        );
+    }
+
+    #[test]
+    fn trace_optical_ray_exits_a_transmissive_collider_parallel_to_entry() {
+        // An angled ray through a slab with parallel entry/exit faces should exit
+        // parallel to how it entered (a textbook lateral-displacement refraction),
+        // not reversed or reflected back.
+        let mut world = World::new();
+        let slab = world
+            .spawn((
+                GlobalTransform::from_translation(Vec3::new(5.0, 0.0, 0.0)),
+                Collider::rectangle(2.0, 20.0),
+                RayOpticsMaterial::Transmissive {
+                    index_of_refraction: 1.5,
+                },
+            ))
+            .id();
+        // An opaque wall behind the slab, so the path records where the ray goes
+        // after leaving the slab.
+        world.spawn((
+            GlobalTransform::from_translation(Vec3::new(20.0, 0.0, 0.0)),
+            Collider::rectangle(2.0, 20.0),
+        ));
+
+        let mut state = SystemState::<SpatialQuery>::new(&mut world);
+        let spatial_query = state.get(&world);
+
+        let entry_direction = Vector::new(1.0, 0.2).normalize();
+        let path = spatial_query.trace_optical_ray(
+            Vector::ZERO,
+            Direction2d::new_unchecked(entry_direction),
+            100.0,
+            5,
+            0.0,
+            SpatialQueryFilter::default(),
+        );
+
+        assert_eq!(
+            path.len(),
+            3,
+            "expected an entry hit, an exit hit, and a hit on the wall behind the slab"
+        );
+        assert_eq!(path[0].hit.entity, slab);
+        assert_eq!(path[1].hit.entity, slab);
+
+        let exit_direction = (path[2].hit.point - path[1].hit.point).normalize();
+        assert!(
+            exit_direction.distance(entry_direction) < 1e-5,
+            "exit direction {exit_direction:?} should be parallel to the entry \
+             direction {entry_direction:?} for a slab with parallel faces"
+        );
+    }
+}